@@ -0,0 +1,44 @@
+//! Aliases the supported `opentelemetry` / `tracing-opentelemetry` release
+//! pair behind mutually-exclusive cargo features, following the
+//! version-matrix pattern used by client instrumentation crates (e.g.
+//! tracing-actix-web's `opentelemetry_0_1X` features), so downstream users
+//! aren't forced onto a single pinned `opentelemetry` release.
+//!
+//! Every use site in this crate should import `opentelemetry` and
+//! `tracing_opentelemetry` from here rather than depending on the extern
+//! crates directly.
+//!
+//! The accompanying `Cargo.toml` renames each supported release to a
+//! feature-specific package name, e.g.:
+//!
+//! ```toml
+//! [dependencies]
+//! opentelemetry_0_24_pkg = { package = "opentelemetry", version = "0.24", optional = true }
+//! tracing-opentelemetry_0_25_pkg = { package = "tracing-opentelemetry", version = "0.25", optional = true }
+//! opentelemetry_0_25_pkg = { package = "opentelemetry", version = "0.25", optional = true }
+//! tracing-opentelemetry_0_26_pkg = { package = "tracing-opentelemetry", version = "0.26", optional = true }
+//!
+//! [features]
+//! opentelemetry_0_24 = ["dep:opentelemetry_0_24_pkg", "dep:tracing-opentelemetry_0_25_pkg"]
+//! opentelemetry_0_25 = ["dep:opentelemetry_0_25_pkg", "dep:tracing-opentelemetry_0_26_pkg"]
+//! ```
+
+#[cfg(all(feature = "opentelemetry_0_24", feature = "opentelemetry_0_25"))]
+compile_error!(
+    "exactly one `opentelemetry_0_XX` feature must be enabled, but both `opentelemetry_0_24` and `opentelemetry_0_25` are"
+);
+
+#[cfg(not(any(feature = "opentelemetry_0_24", feature = "opentelemetry_0_25")))]
+compile_error!(
+    "exactly one `opentelemetry_0_XX` feature must be enabled, e.g. `opentelemetry_0_25`"
+);
+
+#[cfg(feature = "opentelemetry_0_24")]
+pub(crate) use opentelemetry_0_24_pkg as opentelemetry;
+#[cfg(feature = "opentelemetry_0_24")]
+pub(crate) use tracing_opentelemetry_0_25_pkg as tracing_opentelemetry;
+
+#[cfg(feature = "opentelemetry_0_25")]
+pub(crate) use opentelemetry_0_25_pkg as opentelemetry;
+#[cfg(feature = "opentelemetry_0_25")]
+pub(crate) use tracing_opentelemetry_0_26_pkg as tracing_opentelemetry;
@@ -1,9 +1,14 @@
+use crate::compat::{opentelemetry, tracing_opentelemetry};
+use bytes::Bytes;
+use http_body::Body as HttpBody;
 use hyper::service::Service;
 use hyper::{Body, HeaderMap};
 use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::TraceContextExt;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use tonic::body::BoxBody;
+use tonic::Status;
 use tower::Layer;
 use tracing::{info_span, Instrument, Span};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
@@ -44,17 +49,36 @@ where
         let clone = self.inner.clone();
         let mut inner = std::mem::replace(&mut self.inner, clone);
 
-        Box::pin(async move {
-            let span = create_server_span(req.headers().clone());
+        let grpc_method = GrpcMethod::parse(req.uri().path());
+        let span = create_server_span(req.headers().clone(), &grpc_method);
 
+        Box::pin(async move {
             match inner.call(req).instrument(span.clone()).await {
                 Ok(response) => {
-                    span.record("otel.status_code", "ok");
                     span.record(
                         "http.response.status_code",
                         tracing::field::debug(response.status()),
                     );
-                    Ok(response)
+
+                    match grpc_status_from_headers(response.headers()) {
+                        // Trailers-only response (e.g. an immediate error):
+                        // grpc-status is already present as a header.
+                        Some(status) => {
+                            record_grpc_status(&span, status);
+                            Ok(response)
+                        }
+                        // Otherwise grpc-status is only known once the
+                        // trailers have been read off the response body, so
+                        // wrap it to observe them once the caller drains it.
+                        None => {
+                            let (parts, body) = response.into_parts();
+                            let body = BoxBody::new(GrpcStatusBody {
+                                inner: body,
+                                span: span.clone(),
+                            });
+                            Ok(hyper::Response::from_parts(parts, body))
+                        }
+                    }
                 }
                 Err(err) => {
                     span.record("otel.status_code", "error");
@@ -65,21 +89,208 @@ where
     }
 }
 
-fn create_server_span(header_map: HeaderMap) -> Span {
+/// The fully-qualified gRPC method parsed out of a request URI's path, which
+/// tonic always shapes as `/package.Service/Method`.
+struct GrpcMethod {
+    service: String,
+    method: String,
+}
+
+impl GrpcMethod {
+    fn parse(path: &str) -> Self {
+        let mut segments = path.trim_start_matches('/').splitn(2, '/');
+        let service = segments.next().unwrap_or("unknown").to_string();
+        let method = segments.next().unwrap_or("unknown").to_string();
+        Self { service, method }
+    }
+}
+
+fn create_server_span(header_map: HeaderMap, grpc_method: &GrpcMethod) -> Span {
     let span = info_span!(
         "tonic",
         otel.kind = "server",
+        otel.name = format!("{}/{}", grpc_method.service, grpc_method.method),
         otel.status_code = tracing::field::Empty,
         http.response.status_code = tracing::field::Empty,
-        foo = tracing::field::Empty,
+        rpc.system = "grpc",
+        rpc.service = %grpc_method.service,
+        rpc.method = %grpc_method.method,
+        rpc.grpc.status_code = tracing::field::Empty,
+        span_id = tracing::field::Empty,
+        trace_id = tracing::field::Empty,
     );
     let ctx = TonicPropagationContext::new(header_map);
 
     span.set_parent(ctx.extract());
 
+    let otel_context = span.context();
+    let span_context = otel_context.span().span_context().clone();
+    span.record("trace_id", span_context.trace_id().to_string().as_str());
+    span.record("span_id", span_context.span_id().to_string().as_str());
+
     span
 }
 
+fn grpc_status_from_headers(headers: &HeaderMap) -> Option<i32> {
+    headers
+        .get("grpc-status")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+fn record_grpc_status(span: &Span, status: i32) {
+    span.record("rpc.grpc.status_code", status);
+    if status == 0 {
+        span.record("otel.status_code", "ok");
+    } else {
+        span.record("otel.status_code", "error");
+    }
+}
+
+/// Wraps a gRPC response body to record `rpc.grpc.status_code` once the
+/// trailing `grpc-status` is read off the body, which is where tonic puts it
+/// for any response that isn't a Trailers-Only error.
+struct GrpcStatusBody {
+    inner: BoxBody,
+    span: Span,
+}
+
+impl HttpBody for GrpcStatusBody {
+    type Data = Bytes;
+    type Error = Status;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let this = self.get_mut();
+        Pin::new(&mut this.inner).poll_data(cx)
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.inner).poll_trailers(cx);
+        if let Poll::Ready(Ok(Some(trailers))) = &result {
+            if let Some(status) = grpc_status_from_headers(trailers) {
+                record_grpc_status(&this.span, status);
+            }
+        }
+        result
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{create_server_span, GrpcMethod};
+    use hyper::HeaderMap;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::Metadata;
+
+    #[derive(Default)]
+    struct RecordingSubscriber {
+        fields: Mutex<HashMap<String, String>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{value:?}"));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl tracing::Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut fields = self.fields.lock().unwrap();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            let mut fields = self.fields.lock().unwrap();
+            values.record(&mut FieldVisitor(&mut fields));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, _event: &tracing::Event<'_>) {}
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn records_trace_id_and_span_id_as_strings() {
+        let subscriber = RecordingSubscriber::default();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let grpc_method = GrpcMethod::parse("/package.Service/Method");
+            let _span = create_server_span(HeaderMap::new(), &grpc_method);
+
+            tracing::dispatcher::get_default(|dispatch| {
+                let subscriber = dispatch
+                    .downcast_ref::<RecordingSubscriber>()
+                    .expect("RecordingSubscriber");
+                let fields = subscriber.fields.lock().unwrap();
+                assert!(fields.contains_key("trace_id"));
+                assert!(fields.contains_key("span_id"));
+                // Plain hex strings (32 and 16 digits), not debug-quoted.
+                assert_eq!(fields["trace_id"].len(), 32);
+                assert_eq!(fields["span_id"].len(), 16);
+            });
+        });
+    }
+
+    #[test]
+    fn parses_service_and_method() {
+        let grpc_method = GrpcMethod::parse("/package.Service/Method");
+        assert_eq!(grpc_method.service, "package.Service");
+        assert_eq!(grpc_method.method, "Method");
+    }
+
+    #[test]
+    fn defaults_method_to_unknown_when_missing() {
+        let grpc_method = GrpcMethod::parse("/package.Service");
+        assert_eq!(grpc_method.service, "package.Service");
+        assert_eq!(grpc_method.method, "unknown");
+    }
+
+    #[test]
+    fn an_empty_path_yields_an_empty_service_not_unknown() {
+        // `splitn` yields one empty segment for an empty string rather than
+        // no segments at all, so this doesn't hit the `unwrap_or("unknown")`
+        // fallback the way a missing method segment does.
+        let grpc_method = GrpcMethod::parse("");
+        assert_eq!(grpc_method.service, "");
+        assert_eq!(grpc_method.method, "unknown");
+    }
+
+    #[test]
+    fn a_trailing_slash_is_kept_as_part_of_the_method() {
+        // `splitn(2, '/')` only splits once, so anything after the method's
+        // own `/` separator — including a trailing slash — stays in `method`.
+        let grpc_method = GrpcMethod::parse("/package.Service/Method/");
+        assert_eq!(grpc_method.service, "package.Service");
+        assert_eq!(grpc_method.method, "Method/");
+    }
+}
+
 // Can't use the http one until Tonic upgrades to hyper 1.x
 struct TonicPropagationContext(HeaderMap);
 
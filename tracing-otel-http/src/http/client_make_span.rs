@@ -0,0 +1,152 @@
+use crate::compat::opentelemetry;
+use crate::http::http::HttpVersion;
+use http::Request;
+use opentelemetry::propagation::Injector;
+use opentelemetry::{global, Context};
+use tracing::{Level, Span};
+
+/// Creates a new span for an outgoing HTTP request.
+///
+/// Trace span fields are compliant with the [OpenTelemetry HTTP client] specification.
+///
+/// [OpenTelemetry HTTP client]: https://opentelemetry.io/docs/specs/semconv/http/http-spans/
+#[derive(Debug, Clone)]
+pub struct MakeClientSpan {
+    level: Level,
+    component: String,
+    include_headers: bool,
+}
+
+impl MakeClientSpan {
+    /// Create a new `MakeClientSpan`.
+    pub fn new() -> Self {
+        Self {
+            level: Level::DEBUG,
+            component: "tower.request".to_string(),
+            include_headers: false,
+        }
+    }
+
+    /// Set the [`Level`] used for the [`Span`].
+    ///
+    /// Defaults to [`Level::DEBUG`].
+    ///
+    /// [tracing span]: https://docs.rs/tracing/latest/tracing/#spans
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the component name used for the [`Span`].
+    ///
+    /// Defaults to `tower.request`.
+    ///
+    /// [`Span`]: Span
+    pub fn component(mut self, component: &str) -> Self {
+        self.component = component.to_string();
+        self
+    }
+
+    /// Include request headers on the [`Span`].
+    ///
+    /// By default, headers are not included.
+    ///
+    /// [`Span`]: Span
+    pub fn include_headers(mut self, include_headers: bool) -> Self {
+        self.include_headers = include_headers;
+        self
+    }
+
+    /// Create the [`Span`] for an outgoing request.
+    ///
+    /// Unlike [`MakeServerSpan`], this isn't wired through a [`MakeSpan`]
+    /// implementation: the caller still needs a mutable reference to the
+    /// request to inject the span's context into its headers afterwards.
+    ///
+    /// [`MakeServerSpan`]: crate::http::server_make_span::MakeServerSpan
+    /// [`MakeSpan`]: tower_http::trace::MakeSpan
+    pub(crate) fn make_span<B>(&self, request: &Request<B>) -> Span {
+        let (hostname, port) = if let Some(host) = request.uri().host() {
+            let port = request.uri().port_u16().unwrap_or(80);
+            (host, port)
+        } else {
+            ("unknown", 0)
+        };
+
+        let http_version: HttpVersion = request.version().into();
+
+        // This ugly macro is needed, unfortunately, because `tracing::span!`
+        // required the level argument to be static. Meaning we can't just pass
+        // `self.level`.
+        macro_rules! make_span {
+            ($level:expr) => {
+                tracing::span!(
+                    $level,
+                    "request",
+                    component = %self.component,
+                    headers = tracing::field::Empty,
+                    otel.kind = "client",
+                    otel.status_code = tracing::field::Empty,
+                    http.request.method = %request.method(), // OTEL required
+                    http.response.status_code = tracing::field::Empty,
+                    network.protocol.name = http_version.protocol,
+                    network.protocol.version = http_version.version,
+                    server.address = hostname,
+                    server.port = port,
+                    url.full = %request.uri(),
+                )
+            }
+        }
+
+        let span = match self.level {
+            Level::ERROR => make_span!(Level::ERROR),
+            Level::WARN => make_span!(Level::WARN),
+            Level::INFO => make_span!(Level::INFO),
+            Level::DEBUG => make_span!(Level::DEBUG),
+            Level::TRACE => make_span!(Level::TRACE),
+        };
+
+        if self.include_headers {
+            span.record("headers", tracing::field::debug(request.headers()));
+        }
+
+        span
+    }
+}
+
+impl Default for MakeClientSpan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Injects a [`Context`] into outgoing request headers.
+///
+/// Mirrors [`super::server_make_span::PropagationContext`], but implements
+/// [`Injector`] over a mutable [`HeaderMap`] instead of [`Extractor`] over an
+/// owned one, since injecting the current span requires mutating the
+/// outgoing request rather than just reading the incoming one.
+///
+/// [`HeaderMap`]: http::HeaderMap
+/// [`Extractor`]: opentelemetry::propagation::Extractor
+pub(crate) struct PropagationContext<'a>(&'a mut http::HeaderMap);
+
+impl<'a> PropagationContext<'a> {
+    pub fn new(header_map: &'a mut http::HeaderMap) -> Self {
+        Self(header_map)
+    }
+
+    pub fn inject(&mut self, cx: &Context) {
+        global::get_text_map_propagator(|propagator| propagator.inject_context(cx, self));
+    }
+}
+
+impl<'a> Injector for PropagationContext<'a> {
+    fn set(&mut self, key: &str, value: String) {
+        if let Ok(name) = http::header::HeaderName::from_bytes(key.as_bytes()) {
+            if let Ok(value) = http::header::HeaderValue::from_str(&value) {
+                self.0.insert(name, value);
+            }
+        }
+    }
+}
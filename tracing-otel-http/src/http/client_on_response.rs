@@ -0,0 +1,184 @@
+use http::Response;
+use std::fmt;
+use std::fmt::Debug;
+use std::time::Duration;
+use tower_http::LatencyUnit;
+use tracing::{Level, Span};
+
+enum OpenTelemetryStatusCode {
+    Ok,
+    Error,
+}
+
+impl Debug for OpenTelemetryStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpenTelemetryStatusCode::Ok => write!(f, "OK"),
+            OpenTelemetryStatusCode::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+impl<B> From<&Response<B>> for OpenTelemetryStatusCode {
+    fn from(response: &Response<B>) -> Self {
+        // Unlike the server classifier, a 4xx here is the *callee* telling
+        // *us* our request failed, so it's recorded as an error on the
+        // client span even though it's expected behavior on the server span.
+        if response.status().is_client_error() || response.status().is_server_error() {
+            OpenTelemetryStatusCode::Error
+        } else {
+            OpenTelemetryStatusCode::Ok
+        }
+    }
+}
+
+struct Latency {
+    unit: LatencyUnit,
+    duration: Duration,
+}
+
+impl fmt::Display for Latency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            LatencyUnit::Seconds => write!(f, "{} s", self.duration.as_secs_f64()),
+            LatencyUnit::Millis => write!(f, "{} ms", self.duration.as_millis()),
+            LatencyUnit::Micros => write!(f, "{} μs", self.duration.as_micros()),
+            LatencyUnit::Nanos => write!(f, "{} ns", self.duration.as_nanos()),
+            _ => write!(f, "{} ms", self.duration.as_millis()),
+        }
+    }
+}
+
+/// Records the outcome of an outgoing HTTP request on its [`Span`].
+///
+/// Mirrors [`super::server_on_response::ServerOnResponse`], but is invoked
+/// directly by [`HttpClientTraceLayer`] rather than through [`OnResponse`],
+/// since the client middleware also needs to inject the outgoing request's
+/// headers and isn't otherwise built on [`tower_http::trace::TraceLayer`].
+///
+/// [`HttpClientTraceLayer`]: super::client_trace_layer::HttpClientTraceLayer
+/// [`OnResponse`]: tower_http::trace::OnResponse
+#[derive(Clone, Debug)]
+pub struct ClientOnResponse {
+    level: Level,
+    latency_unit: LatencyUnit,
+    include_headers: bool,
+}
+
+impl Default for ClientOnResponse {
+    fn default() -> Self {
+        Self {
+            level: Level::INFO,
+            latency_unit: LatencyUnit::Millis,
+            include_headers: false,
+        }
+    }
+}
+
+impl ClientOnResponse {
+    /// Create a new `ClientOnResponse`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [`Level`] used for [tracing events].
+    ///
+    /// Defaults to [`Level::INFO`].
+    ///
+    /// [tracing events]: https://docs.rs/tracing/latest/tracing/#events
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Set the [`LatencyUnit`] latencies will be reported in.
+    ///
+    /// Defaults to [`LatencyUnit::Millis`].
+    pub fn latency_unit(mut self, latency_unit: LatencyUnit) -> Self {
+        self.latency_unit = latency_unit;
+        self
+    }
+
+    /// Include response headers on the [`Event`].
+    ///
+    /// By default, headers are not included.
+    ///
+    /// [`Event`]: tracing::Event
+    pub fn include_headers(mut self, include_headers: bool) -> Self {
+        self.include_headers = include_headers;
+        self
+    }
+
+    pub(crate) fn on_response<B>(&self, response: &Response<B>, latency: Duration, span: &Span) {
+        let latency = Latency {
+            unit: self.latency_unit,
+            duration: latency,
+        };
+        let response_headers = self
+            .include_headers
+            .then(|| tracing::field::debug(response.headers()));
+
+        span.record(
+            "otel.status_code",
+            tracing::field::debug(OpenTelemetryStatusCode::from(response)),
+        );
+        span.record(
+            "http.response.status_code",
+            &response.status().as_u16(),
+        );
+
+        // This ugly macro is needed, unfortunately, because `tracing::event!`
+        // requires the level argument to be static. Meaning we can't just
+        // pass `self.level`.
+        macro_rules! emit_event {
+            ($level:expr) => {
+                tracing::event!(
+                    $level,
+                    %latency,
+                    response_headers,
+                    "finished processing request"
+                )
+            };
+        }
+
+        match self.level {
+            Level::ERROR => emit_event!(Level::ERROR),
+            Level::WARN => emit_event!(Level::WARN),
+            Level::INFO => emit_event!(Level::INFO),
+            Level::DEBUG => emit_event!(Level::DEBUG),
+            Level::TRACE => emit_event!(Level::TRACE),
+        }
+    }
+
+    /// Records the outcome of an outgoing request that never produced a
+    /// response at all, e.g. a connection failure or timeout from the inner
+    /// [`Service`], mirroring the gRPC middleware's handling of transport
+    /// errors.
+    ///
+    /// [`Service`]: tower_service::Service
+    pub(crate) fn on_error(&self, latency: Duration, span: &Span) {
+        let latency = Latency {
+            unit: self.latency_unit,
+            duration: latency,
+        };
+
+        span.record("otel.status_code", "error");
+
+        // This ugly macro is needed, unfortunately, because `tracing::event!`
+        // requires the level argument to be static. Meaning we can't just
+        // pass `self.level`.
+        macro_rules! emit_event {
+            ($level:expr) => {
+                tracing::event!($level, %latency, "finished processing request with an error")
+            };
+        }
+
+        match self.level {
+            Level::ERROR => emit_event!(Level::ERROR),
+            Level::WARN => emit_event!(Level::WARN),
+            Level::INFO => emit_event!(Level::INFO),
+            Level::DEBUG => emit_event!(Level::DEBUG),
+            Level::TRACE => emit_event!(Level::TRACE),
+        }
+    }
+}
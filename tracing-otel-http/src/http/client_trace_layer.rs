@@ -0,0 +1,114 @@
+use crate::compat::tracing_opentelemetry;
+use crate::http::client_make_span::{MakeClientSpan, PropagationContext};
+use crate::http::client_on_response::ClientOnResponse;
+use http::{Request, Response};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tower_layer::Layer;
+use tower_service::Service;
+use tracing::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// [`Layer`] that adds Open Telemetry compliant HTTP client [tracing] to a
+/// [`Service`], injecting the current span's context into the outgoing
+/// request's headers before the inner service is called.
+///
+/// See the [module docs](crate::trace) for more details.
+///
+/// [`Layer`]: tower_layer::Layer
+/// [tracing]: https://crates.io/crates/tracing
+/// [`Service`]: tower_service::Service
+#[derive(Debug, Clone, Default)]
+pub struct HttpClientTraceLayer {
+    make_span: MakeClientSpan,
+    on_response: ClientOnResponse,
+}
+
+impl HttpClientTraceLayer {
+    /// Create a new `HttpClientTraceLayer` using the default
+    /// [`MakeClientSpan`] and [`ClientOnResponse`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use a custom [`MakeClientSpan`].
+    pub fn make_span(mut self, make_span: MakeClientSpan) -> Self {
+        self.make_span = make_span;
+        self
+    }
+
+    /// Use a custom [`ClientOnResponse`].
+    pub fn on_response(mut self, on_response: ClientOnResponse) -> Self {
+        self.on_response = on_response;
+        self
+    }
+}
+
+impl<S> Layer<S> for HttpClientTraceLayer {
+    type Service = HttpClientTraceService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HttpClientTraceService {
+            inner,
+            make_span: self.make_span.clone(),
+            on_response: self.on_response.clone(),
+        }
+    }
+}
+
+/// [`Service`] produced by [`HttpClientTraceLayer`].
+///
+/// [`Service`]: tower_service::Service
+#[derive(Debug, Clone)]
+pub struct HttpClientTraceService<S> {
+    inner: S,
+    make_span: MakeClientSpan,
+    on_response: ClientOnResponse,
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for HttpClientTraceService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let span = self.make_span.make_span(&req);
+
+        let cx = span.context();
+        PropagationContext::new(req.headers_mut()).inject(&cx);
+
+        // See https://github.com/tower-rs/tower/issues/547#issuecomment-767629149
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let on_response = self.on_response.clone();
+        let start = Instant::now();
+
+        let fut = async move {
+            let result = inner.call(req).await;
+            match &result {
+                Ok(response) => {
+                    on_response.on_response(response, start.elapsed(), &tracing::Span::current());
+                }
+                Err(_) => {
+                    on_response.on_error(start.elapsed(), &tracing::Span::current());
+                }
+            }
+            result
+        }
+        .instrument(span);
+
+        Box::pin(fut)
+    }
+}
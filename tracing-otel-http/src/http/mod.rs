@@ -1,6 +1,13 @@
 mod http;
+pub mod client_make_span;
+pub mod client_on_response;
+pub mod client_trace_layer;
 pub mod server_make_span;
+pub mod server_metrics;
 pub mod server_on_response;
+mod server_route;
+
+pub use client_trace_layer::{HttpClientTraceLayer, HttpClientTraceService};
 
 /// [`Layer`] that adds Open Telemetry compliant HTTP [tracing] to a [`Service`].
 ///
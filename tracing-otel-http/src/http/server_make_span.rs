@@ -1,6 +1,11 @@
+use crate::compat::{opentelemetry, tracing_opentelemetry};
 use crate::http::http::HttpVersion;
+use crate::http::server_metrics::{record_request_attributes, RequestMetricAttributes, ServerMetrics};
+use crate::http::server_route::RouteMatcher;
 use http::{HeaderMap, Request};
+use opentelemetry::metrics::Meter;
 use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::TraceContextExt;
 use tower_http::trace::MakeSpan;
 use tracing::{Level, Span};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
@@ -16,6 +21,9 @@ pub struct MakeServerSpan {
     component: String,
     include_headers: bool,
     propagate_context: bool,
+    record_trace_id: bool,
+    metrics: Option<ServerMetrics>,
+    route_matcher: RouteMatcher,
 }
 
 /// Foober
@@ -27,6 +35,9 @@ impl MakeServerSpan {
             component: "tower.request".to_string(),
             include_headers: false,
             propagate_context: true,
+            record_trace_id: true,
+            metrics: None,
+            route_matcher: RouteMatcher::default(),
         }
     }
 
@@ -69,6 +80,51 @@ impl MakeServerSpan {
         self.propagate_context = propagate_context;
         self
     }
+
+    /// Record the resolved `trace_id` and `span_id` as fields on the [`Span`].
+    ///
+    /// This lets log lines emitted within the span be correlated to the
+    /// trace without needing the OTEL exporter.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Span`]: Span
+    pub fn record_trace_id(mut self, record_trace_id: bool) -> Self {
+        self.record_trace_id = record_trace_id;
+        self
+    }
+
+    /// Record OTEL HTTP server metrics (`http.server.active_requests`) on
+    /// the given [`Meter`].
+    ///
+    /// By default, no metrics are recorded. Pass the same `Meter` to
+    /// [`ServerOnResponse::metrics`] to also record
+    /// `http.server.request.duration`.
+    ///
+    /// [`ServerOnResponse::metrics`]: super::server_on_response::ServerOnResponse::metrics
+    pub fn metrics(mut self, meter: &Meter) -> Self {
+        self.metrics = Some(ServerMetrics::new(meter));
+        self
+    }
+
+    /// Match the request path against the given route templates (e.g.
+    /// `/users/{id}/orders/{order_id}`) to populate `http.route` and
+    /// `http.path_group` with a low-cardinality value, as the [OTEL HTTP
+    /// specification] requires.
+    ///
+    /// Falls back to the literal request path when no template matches.
+    ///
+    /// By default, no templates are configured and the literal path is used.
+    ///
+    /// [OTEL HTTP specification]: https://opentelemetry.io/docs/specs/semconv/http/http-spans/
+    pub fn route_patterns<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.route_matcher = RouteMatcher::new(patterns);
+        self
+    }
 }
 
 impl Default for MakeServerSpan {
@@ -92,6 +148,7 @@ impl<B> MakeSpan<B> for MakeServerSpan {
         };
 
         let http_version: HttpVersion = request.version().into();
+        let route = self.route_matcher.matched_route(request.uri().path());
         let binding = http::header::HeaderValue::from_static("");
         let user_agent = request
             .headers()
@@ -117,15 +174,17 @@ impl<B> MakeSpan<B> for MakeServerSpan {
                     otel.status_code = tracing::field::Empty,
                     http.host = hostname,
                     http.request.method = %request.method(), // OTEL required
-                    http.route = %request.uri().path(),
-                    http.path_group = tracing::field::Empty,
+                    http.route = route,
+                    http.path_group = route,
                     http.response.status_code = tracing::field::Empty,
                     network.protocol.name = http_version.protocol,
                     network.protocol.version = http_version.version,
                     network.transport = "tcp",
                     server.addresss = hostname,
                     server.port = port,
+                    span_id = tracing::field::Empty,
                     telemetry.sdk.language = "rust",
+                    trace_id = tracing::field::Empty,
                     url.scheme = %request.uri().scheme_str().unwrap_or("httx"),
                     url.path = %request.uri().path(),
                     url.query = %request.uri().query().unwrap_or(""),
@@ -147,6 +206,26 @@ impl<B> MakeSpan<B> for MakeServerSpan {
             span.set_parent(ctx.extract());
         }
 
+        if self.record_trace_id {
+            let otel_context = span.context();
+            let span_context = otel_context.span().span_context().clone();
+            span.record("trace_id", span_context.trace_id().to_string().as_str());
+            span.record("span_id", span_context.span_id().to_string().as_str());
+        }
+
+        if let Some(metrics) = &self.metrics {
+            let attributes = RequestMetricAttributes {
+                method: request.method().to_string(),
+                route: route.to_string(),
+            };
+            metrics.active_requests.add(1, &attributes.as_key_values());
+            // Stashed on the span's extensions so `ServerOnResponse::on_response`,
+            // which only sees the response, can attach the same attributes to
+            // `http.server.request.duration` and decrement `active_requests`
+            // with a matching attribute set.
+            record_request_attributes(&span, attributes);
+        }
+
         span
     }
 }
@@ -173,3 +252,81 @@ impl Extractor for PropagationContext {
         self.0.keys().map(|k| k.as_str()).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MakeServerSpan;
+    use http::Request;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tower_http::trace::MakeSpan;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::Metadata;
+
+    #[derive(Default)]
+    struct RecordingSubscriber {
+        fields: Mutex<HashMap<String, String>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{value:?}"));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl tracing::Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut fields = self.fields.lock().unwrap();
+            attrs.record(&mut FieldVisitor(&mut fields));
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            let mut fields = self.fields.lock().unwrap();
+            values.record(&mut FieldVisitor(&mut fields));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+        fn event(&self, _event: &tracing::Event<'_>) {}
+        fn enter(&self, _span: &Id) {}
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn records_trace_id_and_span_id_as_strings() {
+        let subscriber = RecordingSubscriber::default();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let request = Request::builder()
+                .uri("http://example.com/users/42")
+                .body(())
+                .unwrap();
+
+            let mut make_span = MakeServerSpan::new().propagate_context(false);
+            let _span = MakeSpan::make_span(&mut make_span, &request);
+
+            tracing::dispatcher::get_default(|dispatch| {
+                let subscriber = dispatch
+                    .downcast_ref::<RecordingSubscriber>()
+                    .expect("RecordingSubscriber");
+                let fields = subscriber.fields.lock().unwrap();
+                assert!(fields.contains_key("trace_id"));
+                assert!(fields.contains_key("span_id"));
+                // Plain hex strings (32 and 16 digits), not debug-quoted.
+                assert_eq!(fields["trace_id"].len(), 32);
+                assert_eq!(fields["span_id"].len(), 16);
+            });
+        });
+    }
+}
@@ -0,0 +1,86 @@
+use crate::compat::opentelemetry;
+use opentelemetry::metrics::{Histogram, Meter, UpDownCounter};
+use opentelemetry::KeyValue;
+use tracing::Span;
+use tracing_subscriber::registry::LookupSpan;
+
+/// OTEL HTTP server instruments, shared between [`MakeServerSpan`] and
+/// [`ServerOnResponse`] so both halves of the request/response lifecycle
+/// they already observe can feed the same metrics.
+///
+/// [`MakeServerSpan`]: super::server_make_span::MakeServerSpan
+/// [`ServerOnResponse`]: super::server_on_response::ServerOnResponse
+#[derive(Clone)]
+pub struct ServerMetrics {
+    pub(crate) request_duration: Histogram<f64>,
+    pub(crate) active_requests: UpDownCounter<i64>,
+}
+
+impl ServerMetrics {
+    /// Create the `http.server.request.duration` and
+    /// `http.server.active_requests` instruments on the given [`Meter`].
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            request_duration: meter
+                .f64_histogram("http.server.request.duration")
+                .with_unit("s")
+                .with_description("Duration of HTTP server requests.")
+                .init(),
+            active_requests: meter
+                .i64_up_down_counter("http.server.active_requests")
+                .with_description("Number of in-flight HTTP server requests.")
+                .init(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ServerMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerMetrics").finish_non_exhaustive()
+    }
+}
+
+/// The request-derived attributes `ServerOnResponse` needs but doesn't see,
+/// since `OnResponse::on_response` is only handed the response.
+///
+/// Stashed on the span's extensions by [`MakeServerSpan::make_span`] and
+/// read back by `ServerOnResponse::on_response`, which is the same trick
+/// `tracing-opentelemetry` itself relies on to thread `OtelData` through a
+/// span, so it assumes the active subscriber is (or wraps) a
+/// [`tracing_subscriber::Registry`] the same way that integration already
+/// does.
+///
+/// [`MakeServerSpan::make_span`]: super::server_make_span::MakeServerSpan
+#[derive(Clone)]
+pub(crate) struct RequestMetricAttributes {
+    pub(crate) method: String,
+    pub(crate) route: String,
+}
+
+impl RequestMetricAttributes {
+    pub(crate) fn as_key_values(&self) -> Vec<KeyValue> {
+        vec![
+            KeyValue::new("http.request.method", self.method.clone()),
+            KeyValue::new("http.route", self.route.clone()),
+        ]
+    }
+}
+
+pub(crate) fn record_request_attributes(span: &Span, attributes: RequestMetricAttributes) {
+    span.with_subscriber(|(id, subscriber)| {
+        if let Some(registry) = subscriber.downcast_ref::<tracing_subscriber::Registry>() {
+            if let Some(span_ref) = registry.span(id) {
+                span_ref.extensions_mut().insert(attributes);
+            }
+        }
+    });
+}
+
+pub(crate) fn take_request_attributes(span: &Span) -> Option<RequestMetricAttributes> {
+    span.with_subscriber(|(id, subscriber)| {
+        let registry = subscriber.downcast_ref::<tracing_subscriber::Registry>()?;
+        let span_ref = registry.span(id)?;
+        span_ref.extensions().get::<RequestMetricAttributes>().cloned()
+    })
+    .flatten()
+}
@@ -1,4 +1,9 @@
+use crate::compat::opentelemetry;
+use crate::http::http::HttpVersion;
+use crate::http::server_metrics::{take_request_attributes, ServerMetrics};
 use http::Response;
+use opentelemetry::metrics::Meter;
+use opentelemetry::KeyValue;
 use std::fmt;
 use std::fmt::Debug;
 use std::time::Duration;
@@ -55,6 +60,7 @@ pub struct ServerOnResponse {
     level: Level,
     latency_unit: LatencyUnit,
     include_headers: bool,
+    metrics: Option<ServerMetrics>,
 }
 
 impl Default for ServerOnResponse {
@@ -63,6 +69,7 @@ impl Default for ServerOnResponse {
             level: Level::INFO,
             latency_unit: LatencyUnit::Millis,
             include_headers: false,
+            metrics: None,
         }
     }
 }
@@ -106,6 +113,20 @@ impl ServerOnResponse {
         self.include_headers = include_headers;
         self
     }
+
+    /// Record OTEL HTTP server metrics (`http.server.request.duration`) on
+    /// the given [`Meter`], and decrement `http.server.active_requests` once
+    /// per response.
+    ///
+    /// By default, no metrics are recorded. The matching increment of
+    /// `http.server.active_requests` is made by [`MakeServerSpan::metrics`]
+    /// when it is given the same `Meter`.
+    ///
+    /// [`MakeServerSpan::metrics`]: super::server_make_span::MakeServerSpan::metrics
+    pub fn metrics(mut self, meter: &Meter) -> Self {
+        self.metrics = Some(ServerMetrics::new(meter));
+        self
+    }
 }
 
 impl<B> OnResponse<B> for ServerOnResponse {
@@ -125,6 +146,39 @@ impl<B> OnResponse<B> for ServerOnResponse {
         span.record("status", status(response));
         span.record("http.status_code", &response.status().as_u16());
 
+        if let Some(metrics) = &self.metrics {
+            // `http.request.method`/`http.route` aren't known here, only to
+            // `MakeServerSpan::make_span` which built the span — they're
+            // stashed on the span's extensions there and read back here so
+            // they end up on both instruments (the duration histogram also
+            // gets the status code and protocol version), and so
+            // `active_requests` nets to zero using the same attribute set it
+            // was incremented with.
+            let request_attributes = take_request_attributes(span);
+            let http_version: HttpVersion = response.version().into();
+
+            let mut duration_attributes = request_attributes
+                .as_ref()
+                .map(|attributes| attributes.as_key_values())
+                .unwrap_or_default();
+            duration_attributes.push(KeyValue::new(
+                "http.response.status_code",
+                response.status().as_u16() as i64,
+            ));
+            duration_attributes.push(KeyValue::new(
+                "network.protocol.version",
+                http_version.version,
+            ));
+            metrics
+                .request_duration
+                .record(latency.duration.as_secs_f64(), &duration_attributes);
+
+            let active_requests_attributes = request_attributes
+                .map(|attributes| attributes.as_key_values())
+                .unwrap_or_default();
+            metrics.active_requests.add(-1, &active_requests_attributes);
+        }
+
         tracing::event!(
             Level::INFO,
             %latency,
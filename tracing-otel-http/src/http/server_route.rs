@@ -0,0 +1,171 @@
+/// A compiled route template, e.g. `/users/{id}/orders/{order_id}`, split
+/// into segments once so matching a request path doesn't need to re-parse
+/// the template on every request.
+#[derive(Debug, Clone)]
+struct RoutePattern {
+    template: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Param,
+}
+
+impl RoutePattern {
+    fn new(template: &str) -> Self {
+        let segments: Vec<Segment> = template
+            .trim_start_matches('/')
+            .split('/')
+            .map(|segment| {
+                if segment.starts_with('{') && segment.ends_with('}') {
+                    Segment::Param
+                } else {
+                    Segment::Literal(segment.to_string())
+                }
+            })
+            .collect();
+
+        Self {
+            template: template.to_string(),
+            segments,
+        }
+    }
+
+    /// Number of `{param}` segments, used to rank patterns from most to
+    /// least specific so e.g. `/users/active` is preferred over
+    /// `/users/{id}` regardless of which was registered first.
+    fn param_count(&self) -> usize {
+        self.segments
+            .iter()
+            .filter(|segment| **segment == Segment::Param)
+            .count()
+    }
+
+    fn matches(&self, path: &str) -> bool {
+        let mut path_segments = path.trim_start_matches('/').split('/');
+        let mut pattern_segments = self.segments.iter();
+
+        loop {
+            return match (path_segments.next(), pattern_segments.next()) {
+                (Some(path_segment), Some(Segment::Literal(literal))) => {
+                    if path_segment == literal {
+                        continue;
+                    }
+                    false
+                }
+                (Some(_), Some(Segment::Param)) => continue,
+                (None, None) => true,
+                _ => false,
+            };
+        }
+    }
+}
+
+/// Matches incoming request paths against a configured set of low-cardinality
+/// route templates (e.g. `/users/{id}`), falling back to the literal path
+/// when nothing matches.
+///
+/// When more than one template matches the same path (e.g. `/users/{id}`
+/// and `/users/active`), the template with fewer `{param}` segments wins,
+/// regardless of registration order — so a more specific literal template
+/// is never shadowed by a more general one.
+///
+/// Used to populate `http.route` and `http.path_group` on the [`Span`]
+/// produced by [`MakeServerSpan`] with a value that the [OTEL HTTP
+/// specification] requires to be low-cardinality, which the raw request
+/// path generally isn't once it contains ids.
+///
+/// [`Span`]: tracing::Span
+/// [`MakeServerSpan`]: super::server_make_span::MakeServerSpan
+/// [OTEL HTTP specification]: https://opentelemetry.io/docs/specs/semconv/http/http-spans/
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RouteMatcher {
+    patterns: Vec<RoutePattern>,
+}
+
+impl RouteMatcher {
+    pub(crate) fn new<I, S>(templates: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut patterns: Vec<RoutePattern> = templates
+            .into_iter()
+            .map(|template| RoutePattern::new(template.as_ref()))
+            .collect();
+        patterns.sort_by_key(RoutePattern::param_count);
+
+        Self { patterns }
+    }
+
+    pub(crate) fn matched_route<'a>(&'a self, path: &'a str) -> &'a str {
+        self.patterns
+            .iter()
+            .find(|pattern| pattern.matches(path))
+            .map(|pattern| pattern.template.as_str())
+            .unwrap_or(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RouteMatcher;
+
+    #[test]
+    fn matches_literal_template() {
+        let matcher = RouteMatcher::new(["/health"]);
+        assert_eq!(matcher.matched_route("/health"), "/health");
+    }
+
+    #[test]
+    fn matches_single_param() {
+        let matcher = RouteMatcher::new(["/users/{id}"]);
+        assert_eq!(matcher.matched_route("/users/42"), "/users/{id}");
+    }
+
+    #[test]
+    fn matches_multiple_params() {
+        let matcher = RouteMatcher::new(["/users/{id}/orders/{order_id}"]);
+        assert_eq!(
+            matcher.matched_route("/users/42/orders/9"),
+            "/users/{id}/orders/{order_id}"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_literal_path_when_nothing_matches() {
+        let matcher = RouteMatcher::new(["/users/{id}"]);
+        assert_eq!(matcher.matched_route("/orders/42"), "/orders/42");
+    }
+
+    #[test]
+    fn falls_back_to_literal_path_with_no_patterns_configured() {
+        let matcher = RouteMatcher::default();
+        assert_eq!(matcher.matched_route("/users/42"), "/users/42");
+    }
+
+    #[test]
+    fn does_not_match_a_shorter_or_longer_path() {
+        let matcher = RouteMatcher::new(["/users/{id}"]);
+        assert_eq!(matcher.matched_route("/users"), "/users");
+        assert_eq!(matcher.matched_route("/users/42/orders"), "/users/42/orders");
+    }
+
+    #[test]
+    fn a_trailing_slash_is_not_matched() {
+        // "/users/42/" has a trailing empty segment the template doesn't, so
+        // this intentionally falls back to the literal path rather than
+        // silently matching.
+        let matcher = RouteMatcher::new(["/users/{id}"]);
+        assert_eq!(matcher.matched_route("/users/42/"), "/users/42/");
+    }
+
+    #[test]
+    fn literal_templates_win_over_params_regardless_of_registration_order() {
+        let matcher = RouteMatcher::new(["/users/{id}", "/users/active"]);
+        assert_eq!(matcher.matched_route("/users/active"), "/users/active");
+        assert_eq!(matcher.matched_route("/users/42"), "/users/{id}");
+    }
+}